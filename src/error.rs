@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+/// Crate-wide error type for fallible operations against the Hank host.
+///
+/// Every host call and every `#[plugin_fn]` entry point should surface failures through this
+/// type instead of panicking, since a panic in a plugin takes down the whole wasm instance.
+#[derive(Error, Debug)]
+pub enum HankError {
+    #[error("plugin did not initialize global HANK")]
+    NotInitialized,
+
+    #[error("host call failed: {0}")]
+    HostCall(String),
+
+    #[error("failed to deserialize row {row}: {source}")]
+    Deserialize {
+        row: usize,
+        source: serde_json::Error,
+    },
+
+    #[error("database error: {0}")]
+    Db(String),
+
+    #[error("failed to parse plugin manifest: {0}")]
+    Manifest(#[from] serde_json::Error),
+
+    #[error("migration {version} was edited after being applied (checksum mismatch)")]
+    MigrationChecksumMismatch { version: u32 },
+}
@@ -1,3 +1,9 @@
+mod error;
+mod lifecycle;
+mod messaging;
+mod migrations;
+mod scheduler;
+
 use extism_pdk::{host_fn, Prost};
 use hank_types::cron::{CronJob, OneShotJob};
 use hank_types::database::{PreparedStatement, Results};
@@ -6,21 +12,26 @@ use hank_types::message::{Message, Reaction};
 use hank_types::plugin::{CommandContext, Instruction, Metadata};
 use hank_types::scheduled_job_input::ScheduledJob;
 use hank_types::{
-    ChatCommandInput, ChatCommandOutput, ChatMessageInput, ChatMessageOutput, CronInput,
-    CronOutput, DbQueryInput, DbQueryOutput, GetMetadataInput, GetMetadataOutput, InitializeInput,
-    InitializeOutput, InstallInput, InstallOutput, InstructPluginInput, InstructPluginOutput,
-    LoadPluginInput, LoadPluginOutput, OneShotInput, OneShotOutput, ReactInput, ReactOutput,
-    ReloadPluginInput, ReloadPluginOutput, ScheduledJobInput, ScheduledJobOutput, SendMessageInput,
-    SendMessageOutput, UnloadPluginInput, UnloadPluginOutput,
+    CancelJobInput, CancelJobOutput, ChatCommandInput, ChatCommandOutput, ChatMessageInput,
+    ChatMessageOutput, CronInput, CronOutput, DbQueryInput, DbQueryOutput, GetMetadataInput,
+    GetMetadataOutput, InitializeInput, InitializeOutput, InstallInput, InstallOutput,
+    InstructPluginInput, InstructPluginOutput, LoadPluginInput, LoadPluginOutput, OneShotInput,
+    OneShotOutput, ReactInput, ReactOutput, ReloadPluginInput, ReloadPluginOutput, ResumeInput,
+    ResumeOutput, ScheduledJobInput, ScheduledJobOutput, SendMessageInput, SendMessageOutput,
+    SuspendInput, SuspendOutput, UnloadInput, UnloadOutput, UnloadPluginInput, UnloadPluginOutput,
 };
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::{LazyLock, RwLock};
 
+pub use error::HankError;
 pub use extism_pdk::{
     debug, error, http, info, plugin_fn, warn, FnResult, HttpRequest, HttpResponse,
 };
+pub use lifecycle::PluginState;
+pub use migrations::Migration;
 pub use prost::Message as ProstMessage;
+pub use scheduler::{Entry, JobHandle, JobKind, JobState, RetryPolicy};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -29,6 +40,7 @@ extern "ExtismHost" {
     pub fn db_query(input: Prost<DbQueryInput>) -> Prost<DbQueryOutput>;
     pub fn cron(input: Prost<CronInput>) -> Prost<CronOutput>;
     pub fn one_shot(input: Prost<OneShotInput>) -> Prost<OneShotOutput>;
+    pub fn cancel_job(input: Prost<CancelJobInput>) -> Prost<CancelJobOutput>;
     pub fn reload_plugin(input: Prost<ReloadPluginInput>) -> Prost<ReloadPluginOutput>;
     pub fn load_plugin(input: Prost<LoadPluginInput>) -> Prost<LoadPluginOutput>;
     pub fn unload_plugin(input: Prost<UnloadPluginInput>) -> Prost<UnloadPluginOutput>;
@@ -42,10 +54,15 @@ pub struct Hank {
     initialize_handler: Option<fn()>,
     chat_message_handler: Option<fn(message: Message)>,
     chat_command_handler: Option<fn(context: CommandContext, message: Message)>,
-    scheduled_jobs: HashMap<String, fn()>,
+    instruction_handler: Option<fn(Instruction) -> Vec<u8>>,
+    suspend_handler: Option<fn()>,
+    resume_handler: Option<fn()>,
+    unload_handler: Option<fn()>,
+    scheduled_jobs: HashMap<String, Entry>,
+    migrations: Vec<Migration>,
+    state: PluginState,
 }
 
-// @TODO error handling
 impl Hank {
     pub fn new(metadata: impl Into<Metadata>) -> Self {
         Self {
@@ -93,32 +110,130 @@ impl Hank {
         self.chat_command_handler = Some(handler);
     }
 
-    pub fn scheduled_job_handler(&self, uuid: String) {
-        if let Some(job) = self.scheduled_jobs.get(&uuid) {
-            job();
+    pub fn state(&self) -> PluginState {
+        self.state
+    }
+
+    pub fn suspend_handler(&self) -> Option<fn()> {
+        self.suspend_handler
+    }
+
+    pub fn register_suspend_handler(&mut self, handler: fn()) {
+        self.suspend_handler = Some(handler);
+    }
+
+    pub fn resume_handler(&self) -> Option<fn()> {
+        self.resume_handler
+    }
+
+    pub fn register_resume_handler(&mut self, handler: fn()) {
+        self.resume_handler = Some(handler);
+    }
+
+    pub fn unload_handler(&self) -> Option<fn()> {
+        self.unload_handler
+    }
+
+    pub fn register_unload_handler(&mut self, handler: fn()) {
+        self.unload_handler = Some(handler);
+    }
+
+    pub fn instruction_handler(&self) -> Option<fn(Instruction) -> Vec<u8>> {
+        self.instruction_handler
+    }
+
+    pub fn register_instruction_handler(&mut self, handler: fn(Instruction) -> Vec<u8>) {
+        self.instruction_handler = Some(handler);
+    }
+
+    pub fn register_migrations(&mut self, migrations: Vec<Migration>) {
+        self.migrations = migrations;
+    }
+
+    pub(crate) fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    pub(crate) fn scheduled_job_entry_mut(&mut self, uuid: &str) -> Option<&mut Entry> {
+        self.scheduled_jobs.get_mut(uuid)
+    }
+
+    pub(crate) fn set_state(&mut self, state: PluginState) {
+        self.state = state;
+    }
+
+    pub(crate) fn suspend_jobs(&mut self) {
+        for entry in self.scheduled_jobs.values_mut() {
+            entry.suspended = true;
+        }
+    }
+
+    pub(crate) fn resume_jobs(&mut self) {
+        for entry in self.scheduled_jobs.values_mut() {
+            entry.suspended = false;
         }
     }
 
-    pub(crate) fn add_cron(&mut self, cron: String, job: fn()) {
-        let uuid = uuid::Uuid::new_v4();
+    pub(crate) fn list_jobs(&self) -> Vec<Entry> {
+        self.scheduled_jobs.values().cloned().collect()
+    }
+
+    pub(crate) fn add_cron(
+        &mut self,
+        cron: String,
+        job: fn() -> Result<(), String>,
+    ) -> Result<JobHandle, HankError> {
+        let uuid = uuid::Uuid::new_v4().to_string();
 
-        self.scheduled_jobs.insert(uuid.to_string(), job);
+        self.scheduled_jobs.insert(
+            uuid.clone(),
+            Entry::new(uuid.clone(), JobKind::Cron, job, None),
+        );
 
         let input = CronInput {
             cron_job: Some(CronJob {
                 cron,
-                job: uuid.to_string(),
+                job: uuid.clone(),
             }),
         };
 
-        let _ = unsafe { crate::cron(Prost(input)) };
+        unsafe { crate::cron(Prost(input)) }
+            .map(|_| JobHandle {
+                uuid,
+                kind: JobKind::Cron,
+            })
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
-    pub(crate) fn add_one_shot(&mut self, duration: i32, job: fn()) {
-        let uuid = uuid::Uuid::new_v4();
+    pub(crate) fn add_one_shot(
+        &mut self,
+        duration: i32,
+        job: fn() -> Result<(), String>,
+        retry: Option<RetryPolicy>,
+    ) -> Result<JobHandle, HankError> {
+        let uuid = uuid::Uuid::new_v4().to_string();
 
-        self.scheduled_jobs.insert(uuid.to_string(), job);
+        self.scheduled_jobs.insert(
+            uuid.clone(),
+            Entry::new(uuid.clone(), JobKind::OneShot, job, retry),
+        );
 
+        let input = OneShotInput {
+            one_shot_job: Some(OneShotJob {
+                duration,
+                job: uuid.clone(),
+            }),
+        };
+
+        unsafe { one_shot(Prost(input)) }
+            .map(|_| JobHandle {
+                uuid,
+                kind: JobKind::OneShot,
+            })
+            .map_err(|e| HankError::HostCall(e.to_string()))
+    }
+
+    pub(crate) fn rearm_one_shot(&mut self, uuid: &str, duration: i32) -> Result<(), HankError> {
         let input = OneShotInput {
             one_shot_job: Some(OneShotJob {
                 duration,
@@ -126,7 +241,9 @@ impl Hank {
             }),
         };
 
-        let _ = unsafe { one_shot(Prost(input)) };
+        unsafe { one_shot(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
     pub fn start(self) -> FnResult<()> {
@@ -135,40 +252,45 @@ impl Hank {
         Ok(())
     }
 
-    pub fn send_message(message: Message) {
+    pub fn send_message(message: Message) -> Result<(), HankError> {
         let input = SendMessageInput {
             message: Some(message),
         };
 
-        let _ = unsafe { send_message(Prost(input)) };
+        unsafe { send_message(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
-    pub fn respond(response: String, message: Message) {
+    pub fn respond(response: String, message: Message) -> Result<(), HankError> {
         let response = Message {
             content: response,
             ..message
         };
-        Self::send_message(response);
+        Self::send_message(response)
     }
 
-    pub fn react(reaction: Reaction) {
+    pub fn react(reaction: Reaction) -> Result<(), HankError> {
         let input = ReactInput {
             reaction: Some(reaction),
         };
 
-        let _ = unsafe { react(Prost(input)) };
+        unsafe { react(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
-    pub fn db_query(statement: PreparedStatement) -> Result<Results, String> {
+    pub fn db_query(statement: PreparedStatement) -> Result<Results, HankError> {
         let input = DbQueryInput {
             prepared_statement: Some(statement),
         };
 
-        let output = unsafe { db_query(Prost(input)) };
-        let Prost(DbQueryOutput { results, error }) = output.unwrap();
+        let output =
+            unsafe { db_query(Prost(input)) }.map_err(|e| HankError::HostCall(e.to_string()))?;
+        let Prost(DbQueryOutput { results, error }) = output;
 
         if let Some(error) = error {
-            Err(error)
+            Err(HankError::Db(error))
         } else {
             Ok(results.unwrap_or_default())
         }
@@ -176,88 +298,180 @@ impl Hank {
 
     pub fn db_fetch<T: for<'a> Deserialize<'a>>(
         statement: PreparedStatement,
-    ) -> Result<Vec<T>, String> {
+    ) -> Result<Vec<T>, HankError> {
         let input = DbQueryInput {
             prepared_statement: Some(statement),
         };
 
-        let output = unsafe { db_query(Prost(input)) };
-        let Prost(DbQueryOutput { results, error }) = output.unwrap();
+        let output =
+            unsafe { db_query(Prost(input)) }.map_err(|e| HankError::HostCall(e.to_string()))?;
+        let Prost(DbQueryOutput { results, error }) = output;
 
         if let Some(error) = error {
-            Err(error)
+            Err(HankError::Db(error))
         } else {
-            Ok(results
+            results
                 .unwrap_or_default()
                 .rows
                 .into_iter()
-                .map(|s| serde_json::from_str(&s).unwrap())
-                .collect())
+                .enumerate()
+                .map(|(row, s)| {
+                    serde_json::from_str(&s)
+                        .map_err(|source| HankError::Deserialize { row, source })
+                })
+                .collect()
         }
     }
 
-    pub fn cron(cron: String, job: fn()) {
+    pub fn cron(cron: String, job: fn() -> Result<(), String>) -> Result<JobHandle, HankError> {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.add_cron(cron, job)
+    }
+
+    pub fn one_shot(
+        duration: i32,
+        job: fn() -> Result<(), String>,
+    ) -> Result<JobHandle, HankError> {
         let mut guard = HANK.write().unwrap();
-        let hank = guard
-            .as_mut()
-            .expect("Plugin did not initialize global HANK");
-        hank.add_cron(cron, job);
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.add_one_shot(duration, job, None)
     }
 
-    pub fn one_shot(duration: i32, job: fn()) {
+    pub fn one_shot_with_retry(
+        duration: i32,
+        job: fn() -> Result<(), String>,
+        retry: RetryPolicy,
+    ) -> Result<JobHandle, HankError> {
         let mut guard = HANK.write().unwrap();
-        let hank = guard
-            .as_mut()
-            .expect("Plugin did not initialize global HANK");
-        hank.add_one_shot(duration, job);
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.add_one_shot(duration, job, Some(retry))
+    }
+
+    pub fn cancel_job(uuid: impl Into<String>) -> Result<(), HankError> {
+        let uuid = uuid.into();
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+
+        if let Some(entry) = hank.scheduled_job_entry_mut(&uuid) {
+            entry.state = JobState::Cancelled;
+        }
+
+        let input = CancelJobInput { job: uuid };
+
+        unsafe { cancel_job(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
+    }
+
+    pub fn list_jobs() -> Result<Vec<Entry>, HankError> {
+        let guard = HANK.read().unwrap();
+        let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
+        Ok(hank.list_jobs())
+    }
+
+    pub fn state() -> Result<PluginState, HankError> {
+        let guard = HANK.read().unwrap();
+        let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
+        Ok(hank.state())
     }
 
     // Escalated privileges necessary for use.
-    pub fn reload_plugin(plugin: impl Into<String>) {
+    pub fn reload_plugin(plugin: impl Into<String>) -> Result<(), HankError> {
         let input = ReloadPluginInput {
             plugin: plugin.into(),
         };
 
-        let _ = unsafe { reload_plugin(Prost(input)) };
+        unsafe { reload_plugin(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
     // Escalated privileges necessary for use.
     pub fn load_plugin(
         wasm: impl Into<Wasm>,
-    ) -> Result<(extism_manifest::Manifest, Metadata), extism_pdk::Error> {
+    ) -> Result<(extism_manifest::Manifest, Metadata), HankError> {
         let input = LoadPluginInput {
             wasm: Some(wasm.into()),
         };
 
-        unsafe { load_plugin(Prost(input)) }.map(
-            |Prost(LoadPluginOutput {
-                 metadata, manifest, ..
-             })| {
-                (
-                    serde_json::from_str(&manifest).expect("valid manifest"),
-                    metadata.expect("ok result"),
-                )
-            },
-        )
+        let Prost(LoadPluginOutput {
+            metadata, manifest, ..
+        }) = unsafe { load_plugin(Prost(input)) }
+            .map_err(|e| HankError::HostCall(e.to_string()))?;
+
+        Ok((
+            serde_json::from_str(&manifest)?,
+            metadata.ok_or_else(|| {
+                HankError::HostCall("load_plugin returned no metadata".into())
+            })?,
+        ))
     }
 
     // Escalated privileges necessary for use.
-    pub fn unload_plugin(plugin: impl Into<String>, cleanup: bool) {
+    pub fn unload_plugin(plugin: impl Into<String>, cleanup: bool) -> Result<(), HankError> {
         let input = UnloadPluginInput {
             plugin: plugin.into(),
             cleanup,
         };
 
-        let _ = unsafe { unload_plugin(Prost(input)) };
+        unsafe { unload_plugin(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
     }
 
     // Escalated privileges necessary for use.
-    pub fn instruct_plugin(instruction: Instruction) {
+    //
+    // `handle_instruct` is the only entry point a plugin can register to receive instructions,
+    // and `register_instruction_handler` always treats inbound `data` as a `query_plugin`
+    // envelope. This function frames `instruction.data` the same way (with an empty correlation
+    // id, since a fire-and-forget call never reads a reply) so it always round-trips through
+    // `decode_envelope` rather than relying on every caller being framed already. Prefer
+    // [`Hank::query_plugin`] for a typed, synchronous request/response instead.
+    #[deprecated(note = "fire-and-forget with no typed reply; use `Hank::query_plugin` instead")]
+    pub fn instruct_plugin(mut instruction: Instruction) -> Result<(), HankError> {
+        instruction.data = messaging::encode_envelope("", &instruction.data);
+
         let input = InstructPluginInput {
             instruction: Some(instruction),
         };
 
-        let _ = unsafe { instruct_plugin(Prost(input)) };
+        unsafe { instruct_plugin(Prost(input)) }
+            .map(|_| ())
+            .map_err(|e| HankError::HostCall(e.to_string()))
+    }
+
+    /// Sends `req` to `plugin` and blocks for its reply, giving compatible plugins a synchronous,
+    /// typed request/response channel instead of the fire-and-forget `instruct_plugin`.
+    // Escalated privileges necessary for use.
+    pub fn query_plugin<Req, Resp>(
+        plugin: impl Into<String>,
+        req: Req,
+    ) -> Result<Resp, HankError>
+    where
+        Req: ProstMessage,
+        Resp: ProstMessage + Default,
+    {
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        let envelope = messaging::encode_envelope(&correlation_id, &req.encode_to_vec());
+
+        let input = InstructPluginInput {
+            instruction: Some(Instruction {
+                plugin: plugin.into(),
+                data: envelope,
+                ..Default::default()
+            }),
+        };
+
+        let Prost(InstructPluginOutput { response, .. }) =
+            unsafe { instruct_plugin(Prost(input)) }
+                .map_err(|e| HankError::HostCall(e.to_string()))?;
+
+        let response =
+            response.ok_or_else(|| HankError::HostCall("plugin sent no reply".into()))?;
+        let (_, payload) = messaging::decode_envelope(&response)?;
+
+        Resp::decode(payload.as_slice()).map_err(|e| HankError::HostCall(e.to_string()))
     }
 }
 
@@ -268,16 +482,14 @@ pub fn handle_chat_command(
     Prost(ChatCommandInput { context, message }): Prost<ChatCommandInput>,
 ) -> FnResult<Prost<ChatCommandOutput>> {
     let guard = HANK.read().unwrap();
-    let hank = guard
-        .as_ref()
-        .expect("Plugin did not initialize global HANK");
+    let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
 
-    hank.chat_command_handler().map(|handler| {
+    if let Some(handler) = hank.chat_command_handler() {
         handler(
-            context.expect("context should exist"),
-            message.expect("message should exist"),
-        )
-    });
+            context.ok_or(HankError::HostCall("missing command context".into()))?,
+            message.ok_or(HankError::HostCall("missing message".into()))?,
+        );
+    }
 
     Ok(Prost(ChatCommandOutput::default()))
 }
@@ -287,12 +499,11 @@ pub fn handle_chat_message(
     Prost(ChatMessageInput { message }): Prost<ChatMessageInput>,
 ) -> FnResult<Prost<ChatMessageOutput>> {
     let guard = HANK.read().unwrap();
-    let hank = guard
-        .as_ref()
-        .expect("Plugin did not initialize global HANK");
+    let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
 
-    hank.chat_message_handler()
-        .map(|handler| handler(message.expect("message should exist")));
+    if let Some(handler) = hank.chat_message_handler() {
+        handler(message.ok_or(HankError::HostCall("missing message".into()))?);
+    }
 
     Ok(Prost(ChatMessageOutput::default()))
 }
@@ -302,9 +513,7 @@ pub fn handle_get_metadata(
     Prost(_input): Prost<GetMetadataInput>,
 ) -> FnResult<Prost<GetMetadataOutput>> {
     let guard = HANK.read().unwrap();
-    let hank = guard
-        .as_ref()
-        .expect("Plugin did not initialize global HANK");
+    let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
 
     Ok(Prost(GetMetadataOutput {
         metadata: Some(hank.metadata()),
@@ -313,15 +522,26 @@ pub fn handle_get_metadata(
 
 #[plugin_fn]
 pub fn handle_install(Prost(_input): Prost<InstallInput>) -> FnResult<Prost<InstallOutput>> {
-    let guard = HANK.read().unwrap();
-    let hank = guard
-        .as_ref()
-        .expect("Plugin did not initialize global HANK");
+    let install_handler = {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        migrations::run(hank.migrations())?;
+        hank.install_handler()
+    };
 
-    if let Some(handler) = hank.install_handler() {
+    if let Some(handler) = install_handler {
         handler();
     }
 
+    // Set only once the handler has actually finished, mirroring how `handle_initialize` doesn't
+    // mark the plugin `Active` until `initialize_handler` has returned; otherwise a handler that
+    // calls `Hank::state()` mid-install would see `Installed` before its own setup is done.
+    {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Installed);
+    }
+
     Ok(Prost(InstallOutput::default()))
 }
 
@@ -332,14 +552,22 @@ pub fn handle_initialize(
     // This needs to be in its own scope to ensure the guard is dropped before we actually run the
     // initialize handler. Otherwise the initialize handler can't mutate the global hank with the
     // Hank::cron and Hank::one_shot functions.
-    {
-        let guard = HANK.read().unwrap();
-        let hank = guard
-            .as_ref()
-            .expect("Plugin did not initialize global HANK");
+    let handler = {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Initialized);
         hank.initialize_handler()
+    };
+
+    if let Some(handler) = handler {
+        handler();
+    }
+
+    {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Active);
     }
-    .map(|handler| handler());
 
     Ok(Prost(InitializeOutput::default()))
 }
@@ -348,17 +576,135 @@ pub fn handle_initialize(
 pub fn handle_scheduled_job(
     Prost(input): Prost<ScheduledJobInput>,
 ) -> FnResult<Prost<ScheduledJobOutput>> {
-    if let Some(scheduled_job) = input.scheduled_job {
-        let job = match scheduled_job {
-            ScheduledJob::CronJob(cron) => cron.job,
-            ScheduledJob::OneShotJob(oneshot) => oneshot.job,
+    let Some(scheduled_job) = input.scheduled_job else {
+        return Ok(Prost(ScheduledJobOutput::default()));
+    };
+
+    let uuid = match scheduled_job {
+        ScheduledJob::CronJob(cron) => cron.job,
+        ScheduledJob::OneShotJob(oneshot) => oneshot.job,
+    };
+
+    let mut guard = HANK.write().unwrap();
+    let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+
+    let job = {
+        let Some(entry) = hank.scheduled_job_entry_mut(&uuid) else {
+            return Ok(Prost(ScheduledJobOutput::default()));
         };
 
-        let guard = HANK.write().unwrap();
-        let hank = guard
-            .as_ref()
-            .expect("Plugin did not initialize global HANK");
-        hank.scheduled_job_handler(job);
+        if entry.suspended || entry.state == JobState::Cancelled {
+            return Ok(Prost(ScheduledJobOutput::default()));
+        }
+
+        entry.state = JobState::Running;
+        entry.job
+    };
+
+    let result = job();
+    let retry_delay = {
+        let entry = hank
+            .scheduled_job_entry_mut(&uuid)
+            .expect("entry disappeared mid-run");
+
+        entry.runs += 1;
+
+        match result {
+            Ok(()) => {
+                entry.state = JobState::Completed;
+                entry.last_error = None;
+                None
+            }
+            Err(error) => {
+                entry.last_error = Some(error);
+                entry.state = JobState::Failed;
+                entry.next_retry_delay()
+            }
+        }
+    };
+
+    if let Some(delay) = retry_delay {
+        hank.rearm_one_shot(&uuid, delay)?;
     }
+
     Ok(Prost(ScheduledJobOutput::default()))
 }
+
+#[plugin_fn]
+pub fn handle_instruct(
+    Prost(input): Prost<InstructPluginInput>,
+) -> FnResult<Prost<InstructPluginOutput>> {
+    let Some(instruction) = input.instruction else {
+        return Ok(Prost(InstructPluginOutput::default()));
+    };
+
+    let guard = HANK.read().unwrap();
+    let hank = guard.as_ref().ok_or(HankError::NotInitialized)?;
+
+    let Some(handler) = hank.instruction_handler() else {
+        return Ok(Prost(InstructPluginOutput::default()));
+    };
+
+    let (correlation_id, payload) = messaging::decode_envelope(&instruction.data)?;
+    let response = handler(Instruction {
+        data: payload,
+        ..instruction
+    });
+    let envelope = messaging::encode_envelope(&correlation_id, &response);
+
+    Ok(Prost(InstructPluginOutput {
+        response: Some(envelope),
+        ..Default::default()
+    }))
+}
+
+#[plugin_fn]
+pub fn handle_suspend(Prost(_input): Prost<SuspendInput>) -> FnResult<Prost<SuspendOutput>> {
+    let handler = {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Suspended);
+        hank.suspend_jobs();
+        hank.suspend_handler()
+    };
+
+    if let Some(handler) = handler {
+        handler();
+    }
+
+    Ok(Prost(SuspendOutput::default()))
+}
+
+#[plugin_fn]
+pub fn handle_resume(Prost(_input): Prost<ResumeInput>) -> FnResult<Prost<ResumeOutput>> {
+    let handler = {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Active);
+        hank.resume_jobs();
+        hank.resume_handler()
+    };
+
+    if let Some(handler) = handler {
+        handler();
+    }
+
+    Ok(Prost(ResumeOutput::default()))
+}
+
+#[plugin_fn]
+pub fn handle_unload(Prost(_input): Prost<UnloadInput>) -> FnResult<Prost<UnloadOutput>> {
+    // Give the plugin a chance to flush state before the host tears it down.
+    let handler = {
+        let mut guard = HANK.write().unwrap();
+        let hank = guard.as_mut().ok_or(HankError::NotInitialized)?;
+        hank.set_state(PluginState::Unloading);
+        hank.unload_handler()
+    };
+
+    if let Some(handler) = handler {
+        handler();
+    }
+
+    Ok(Prost(UnloadOutput::default()))
+}
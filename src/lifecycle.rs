@@ -0,0 +1,15 @@
+/// Where a plugin is in its runtime lifecycle, tracked on [`crate::Hank`] and updated as the host
+/// drives the plugin through install, initialize, suspend/resume, and unload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PluginState {
+    /// Registered via [`crate::Hank::new`] but not yet through `handle_install` — the default
+    /// state, distinct from [`PluginState::Installed`] so a failed or not-yet-run install can't
+    /// be mistaken for a successful one.
+    #[default]
+    Uninstalled,
+    Installed,
+    Initialized,
+    Active,
+    Suspended,
+    Unloading,
+}
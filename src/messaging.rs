@@ -0,0 +1,80 @@
+use crate::error::HankError;
+
+/// Frames a correlation id ahead of an opaque payload so a reply can be matched back to the
+/// request it answers: a big-endian length-prefixed id followed by the raw bytes.
+pub(crate) fn encode_envelope(correlation_id: &str, payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + correlation_id.len() + payload.len());
+    buf.extend_from_slice(&(correlation_id.len() as u32).to_be_bytes());
+    buf.extend_from_slice(correlation_id.as_bytes());
+    buf.extend_from_slice(payload);
+    buf
+}
+
+pub(crate) fn decode_envelope(data: &[u8]) -> Result<(String, Vec<u8>), HankError> {
+    let Some(id_len_bytes) = data.get(0..4) else {
+        return Err(HankError::HostCall(
+            "malformed instruction envelope: missing length prefix".into(),
+        ));
+    };
+    let id_len = u32::from_be_bytes(id_len_bytes.try_into().unwrap()) as usize;
+    let rest = &data[4..];
+
+    let Some(id_bytes) = rest.get(..id_len) else {
+        return Err(HankError::HostCall(
+            "malformed instruction envelope: truncated correlation id".into(),
+        ));
+    };
+    let correlation_id =
+        String::from_utf8(id_bytes.to_vec()).map_err(|e| HankError::HostCall(e.to_string()))?;
+    let payload = rest[id_len..].to_vec();
+
+    Ok((correlation_id, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_correlation_id_and_payload() {
+        let envelope = encode_envelope("req-1", b"hello world");
+        let (correlation_id, payload) = decode_envelope(&envelope).unwrap();
+
+        assert_eq!(correlation_id, "req-1");
+        assert_eq!(payload, b"hello world");
+    }
+
+    #[test]
+    fn round_trips_empty_correlation_id_and_payload() {
+        let envelope = encode_envelope("", &[]);
+        let (correlation_id, payload) = decode_envelope(&envelope).unwrap();
+
+        assert_eq!(correlation_id, "");
+        assert!(payload.is_empty());
+    }
+
+    #[test]
+    fn rejects_missing_length_prefix() {
+        let err = decode_envelope(&[0, 1, 2]).unwrap_err();
+        assert!(matches!(err, HankError::HostCall(_)));
+    }
+
+    #[test]
+    fn rejects_truncated_correlation_id() {
+        // Claims a 10-byte id but only supplies 2 bytes of body.
+        let mut data = 10u32.to_be_bytes().to_vec();
+        data.extend_from_slice(b"ab");
+
+        let err = decode_envelope(&data).unwrap_err();
+        assert!(matches!(err, HankError::HostCall(_)));
+    }
+
+    #[test]
+    fn rejects_non_utf8_correlation_id() {
+        let mut data = 1u32.to_be_bytes().to_vec();
+        data.push(0xff);
+
+        let err = decode_envelope(&data).unwrap_err();
+        assert!(matches!(err, HankError::HostCall(_)));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::HankError;
+use crate::Hank;
+use hank_types::database::PreparedStatement;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const MIGRATIONS_TABLE_DDL: &str = "CREATE TABLE IF NOT EXISTS _hank_migrations (\
+    version INTEGER PRIMARY KEY, \
+    checksum TEXT NOT NULL\
+)";
+
+/// A single, idempotent schema migration step, applied in ascending `version` order.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub version: u32,
+    pub up: String,
+}
+
+impl Migration {
+    pub fn new(version: u32, up: impl Into<String>) -> Self {
+        Self {
+            version,
+            up: up.into(),
+        }
+    }
+
+    fn checksum(&self) -> String {
+        format!("{:08x}", crc32(self.up.as_bytes()))
+    }
+}
+
+/// CRC-32 (IEEE 802.3 polynomial), computed bit-by-bit rather than via a lookup table since this
+/// runs once per migration at install time. Unlike `std::hash::Hasher`'s bundled algorithms
+/// (e.g. `DefaultHasher`), CRC-32 is a fixed, versioned algorithm: the checksum persisted in
+/// `_hank_migrations` stays stable across Rust/std upgrades instead of drifting and tripping
+/// `MigrationChecksumMismatch` for migrations nobody touched.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[derive(Deserialize)]
+struct AppliedMigration {
+    version: u32,
+    checksum: String,
+}
+
+/// Ensures the `_hank_migrations` table exists and applies every migration whose version isn't
+/// recorded there yet, in ascending order. Fails if an already-applied migration's SQL no longer
+/// matches the checksum recorded when it was applied. With no migrations registered it returns
+/// at once and does not touch the database, so the table is not created.
+pub(crate) fn run(migrations: &[Migration]) -> Result<(), HankError> {
+    if migrations.is_empty() {
+        return Ok(());
+    }
+
+    Hank::db_query(PreparedStatement {
+        query: MIGRATIONS_TABLE_DDL.to_string(),
+        params: vec![],
+    })?;
+
+    let applied: HashMap<u32, String> = Hank::db_fetch::<AppliedMigration>(PreparedStatement {
+        query: "SELECT version, checksum FROM _hank_migrations ORDER BY version ASC".to_string(),
+        params: vec![],
+    })?
+    .into_iter()
+    .map(|row| (row.version, row.checksum))
+    .collect();
+
+    let mut pending: Vec<&Migration> = migrations.iter().collect();
+    pending.sort_by_key(|migration| migration.version);
+
+    for migration in pending {
+        let checksum = migration.checksum();
+
+        if let Some(applied_checksum) = applied.get(&migration.version) {
+            if *applied_checksum != checksum {
+                return Err(HankError::MigrationChecksumMismatch {
+                    version: migration.version,
+                });
+            }
+            continue;
+        }
+
+        Hank::db_query(PreparedStatement {
+            query: migration.up.clone(),
+            params: vec![],
+        })?;
+
+        Hank::db_query(PreparedStatement {
+            query: "INSERT INTO _hank_migrations (version, checksum) VALUES (?, ?)".to_string(),
+            params: vec![migration.version.to_string(), checksum],
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_matches_known_vector() {
+        // The standard CRC-32 (IEEE 802.3) check value for the ASCII string "123456789".
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn crc32_is_deterministic_and_order_sensitive() {
+        assert_eq!(crc32(b"abc"), crc32(b"abc"));
+        assert_ne!(crc32(b"abc"), crc32(b"acb"));
+    }
+
+    #[test]
+    fn checksum_is_stable_and_detects_edits() {
+        let migration = Migration::new(1, "CREATE TABLE foo (id INTEGER)");
+        let edited = Migration::new(1, "CREATE TABLE foo (id INTEGER NOT NULL)");
+
+        assert_eq!(migration.checksum(), migration.checksum());
+        assert_ne!(migration.checksum(), edited.checksum());
+    }
+}
@@ -0,0 +1,120 @@
+/// Whether a scheduled job is a recurring cron job or a one-off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    Cron,
+    OneShot,
+}
+
+/// Where a scheduled job is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Pending,
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// Exponential backoff retry policy for a one-shot job.
+///
+/// `backoff_secs` is the delay before the first retry; each subsequent attempt doubles it.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff_secs: i32,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, backoff_secs: i32) -> Self {
+        Self {
+            max_attempts,
+            backoff_secs,
+        }
+    }
+
+    fn delay_for_attempt(&self, attempt: u32) -> i32 {
+        self.backoff_secs.saturating_mul(1 << attempt.min(30))
+    }
+}
+
+/// A handle to a registered scheduled job, returned from [`crate::Hank::cron`] and
+/// [`crate::Hank::one_shot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobHandle {
+    pub uuid: String,
+    pub kind: JobKind,
+}
+
+/// A registry record for a scheduled job, tracking its closure alongside its runtime state.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub uuid: String,
+    pub kind: JobKind,
+    pub state: JobState,
+    pub runs: u32,
+    pub last_error: Option<String>,
+    pub(crate) job: fn() -> Result<(), String>,
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Set while the host has the plugin suspended; [`crate::handle_scheduled_job`] skips runs
+    /// until the plugin resumes.
+    pub(crate) suspended: bool,
+}
+
+impl Entry {
+    pub(crate) fn new(
+        uuid: String,
+        kind: JobKind,
+        job: fn() -> Result<(), String>,
+        retry: Option<RetryPolicy>,
+    ) -> Self {
+        Self {
+            uuid,
+            kind,
+            state: JobState::Pending,
+            runs: 0,
+            last_error: None,
+            job,
+            retry,
+            suspended: false,
+        }
+    }
+
+    pub(crate) fn next_retry_delay(&self) -> Option<i32> {
+        let retry = self.retry?;
+        // `runs` has already been incremented for the run that just failed, so the upcoming
+        // retry is 0-indexed attempt `runs - 1` (the first retry uses `backoff_secs` itself).
+        (self.runs < retry.max_attempts).then(|| retry.delay_for_attempt(self.runs - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(backoff_secs: i32, max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts, backoff_secs)
+    }
+
+    #[test]
+    fn delay_for_attempt_doubles_from_backoff_secs() {
+        let retry = policy(5, 10);
+        assert_eq!(retry.delay_for_attempt(0), 5);
+        assert_eq!(retry.delay_for_attempt(1), 10);
+        assert_eq!(retry.delay_for_attempt(2), 20);
+        assert_eq!(retry.delay_for_attempt(3), 40);
+    }
+
+    #[test]
+    fn next_retry_delay_uses_backoff_secs_for_first_retry() {
+        let mut entry = Entry::new("job".into(), JobKind::OneShot, || Ok(()), Some(policy(5, 3)));
+
+        entry.runs += 1;
+        assert_eq!(entry.next_retry_delay(), Some(5));
+
+        entry.runs += 1;
+        assert_eq!(entry.next_retry_delay(), Some(10));
+
+        entry.runs += 1;
+        assert_eq!(entry.next_retry_delay(), None);
+    }
+}